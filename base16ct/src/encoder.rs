@@ -0,0 +1,146 @@
+//! Buffered streaming hex encoder.
+
+use crate::{lower, upper, Error};
+use core::str;
+
+/// Which case to encode a [`BufEncoder`]'s output in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Case {
+    /// Lower case
+    Lower,
+    /// Upper case
+    Upper,
+}
+
+/// Write-only wrapper around a byte buffer which only exposes appending to
+/// it, its current length, and its remaining space.
+///
+/// Keeping this API narrow means a future switch to a `MaybeUninit`-backed
+/// buffer (to skip zero-initializing bytes that are about to be
+/// overwritten anyway) can happen without breaking callers.
+pub struct OutBytes<'o> {
+    bytes: &'o mut [u8],
+    position: usize,
+}
+
+impl<'o> OutBytes<'o> {
+    /// Wrap the given byte buffer for writing, starting from an empty
+    /// (zero-length) state.
+    pub fn new(bytes: &'o mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.position
+    }
+
+    /// Have any bytes been written yet?
+    pub fn is_empty(&self) -> bool {
+        self.position == 0
+    }
+
+    /// Number of bytes of capacity remaining.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Append the given bytes, returning [`Error::InvalidLength`] if there
+    /// isn't enough remaining capacity.
+    pub fn append(&mut self, slice: &[u8]) -> Result<(), Error> {
+        let end = self
+            .position
+            .checked_add(slice.len())
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(Error::InvalidLength)?;
+
+        self.bytes[self.position..end].copy_from_slice(slice);
+        self.position = end;
+        Ok(())
+    }
+
+    /// Reset this buffer back to empty.
+    pub(crate) fn clear(&mut self) {
+        self.position = 0;
+    }
+
+    /// Borrow the bytes written so far.
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.position]
+    }
+}
+
+/// Buffered, allocation-free streaming Base16 (hex) encoder.
+///
+/// Encodes bytes into an internal [`OutBytes`] buffer two hex chars at a
+/// time, avoiding a `write!("{:02x}")` call per byte. Useful for encoding
+/// large or chunked inputs (e.g. the output of a hash function in a loop).
+///
+/// # Examples
+/// ```
+/// use base16ct::{BufEncoder, Case};
+///
+/// let mut buf = [0u8; 4];
+/// let mut encoder = BufEncoder::new(&mut buf, Case::Lower);
+/// encoder.put_bytes(&[0xab, 0xcd]).unwrap();
+/// assert_eq!(encoder.as_str(), "abcd");
+///
+/// // the buffer is exactly full now; one more byte doesn't fit
+/// assert_eq!(encoder.put_byte(0x12), Err(base16ct::Error::InvalidLength));
+/// // ...and the rejected write left the encoder's state untouched
+/// assert_eq!(encoder.as_str(), "abcd");
+///
+/// encoder.clear();
+/// assert_eq!(encoder.as_str(), "");
+/// encoder.put_byte(0x12).unwrap();
+/// assert_eq!(encoder.as_str(), "12");
+/// ```
+pub struct BufEncoder<'o> {
+    out: OutBytes<'o>,
+    case: Case,
+}
+
+impl<'o> BufEncoder<'o> {
+    /// Create a new [`BufEncoder`] which writes into `buf` using the given
+    /// [`Case`].
+    pub fn new(buf: &'o mut [u8], case: Case) -> Self {
+        Self {
+            out: OutBytes::new(buf),
+            case,
+        }
+    }
+
+    /// Encode a single byte, appending it to the internal buffer.
+    pub fn put_byte(&mut self, byte: u8) -> Result<(), Error> {
+        self.put_bytes(&[byte])
+    }
+
+    /// Encode a slice of bytes, appending them to the internal buffer.
+    pub fn put_bytes(&mut self, src: &[u8]) -> Result<(), Error> {
+        let encode_nibble = match self.case {
+            Case::Lower => lower::encode_nibble,
+            Case::Upper => upper::encode_nibble,
+        };
+
+        if src.len() * 2 > self.out.remaining() {
+            return Err(Error::InvalidLength);
+        }
+
+        for &byte in src {
+            self.out
+                .append(&[encode_nibble(byte >> 4), encode_nibble(byte & 0xf)])?;
+        }
+
+        Ok(())
+    }
+
+    /// Borrow the hex encoded so far as a `str`.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(self.out.as_bytes()).expect("hex output is always valid UTF-8")
+    }
+
+    /// Reset this encoder back to empty, ready to encode the next chunk.
+    pub fn clear(&mut self) {
+        self.out.clear();
+    }
+}
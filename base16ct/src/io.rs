@@ -0,0 +1,203 @@
+//! [`std::io`] streaming encoder/decoder adapters.
+
+use crate::{lower, mixed, Error};
+use std::io::{self, Read, Write};
+
+/// Wraps a [`Write`], encoding every byte written to it as two lower-case
+/// hex chars before forwarding to the inner writer.
+///
+/// This lets callers pipe arbitrary streams through hex without buffering
+/// the whole payload.
+///
+/// # Examples
+/// ```
+/// use base16ct::io::HexWriter;
+/// use std::io::Write;
+///
+/// let mut out = Vec::new();
+/// let mut w = HexWriter::new(&mut out);
+/// w.write_all(&[0xab, 0xcd, 0x12, 0x34])?;
+/// w.flush()?;
+/// assert_eq!(out, b"abcd1234");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct HexWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> HexWriter<W> {
+    /// Wrap the given writer.
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Consume `self`, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for HexWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        const CHUNK_LEN: usize = 512;
+        let mut hex_buf = [0u8; CHUNK_LEN * 2];
+
+        for chunk in buf.chunks(CHUNK_LEN) {
+            let mut len = 0;
+
+            for &byte in chunk {
+                hex_buf[len] = lower::encode_nibble(byte >> 4);
+                hex_buf[len + 1] = lower::encode_nibble(byte & 0xf);
+                len += 2;
+            }
+
+            self.inner.write_all(&hex_buf[..len])?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+const BUF_LEN: usize = 4096;
+
+/// Wraps a [`Read`], decoding hex (either case) read from it into raw
+/// bytes.
+///
+/// A chunk boundary may split a byte's pair of hex chars in two, so a
+/// decoded high nibble is buffered across `read` calls until its matching
+/// low nibble arrives.
+///
+/// # Examples
+/// ```
+/// use base16ct::io::HexReader;
+/// use std::io::Read;
+///
+/// let mut r = HexReader::new(&b"abCD1234"[..]);
+/// let mut buf = Vec::new();
+/// r.read_to_end(&mut buf)?;
+/// assert_eq!(buf, [0xab, 0xcd, 0x12, 0x34]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// Hex chars split across separate `read` calls on the underlying reader
+/// (e.g. a fragmented socket read) still decode correctly:
+/// ```
+/// use base16ct::io::HexReader;
+/// use std::io::Read;
+///
+/// /// Drip-feeds its input one byte at a time.
+/// struct OneByteAtATime<'a>(&'a [u8]);
+///
+/// impl<'a> Read for OneByteAtATime<'a> {
+///     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+///         match self.0.split_first() {
+///             Some((&byte, rest)) if !buf.is_empty() => {
+///                 buf[0] = byte;
+///                 self.0 = rest;
+///                 Ok(1)
+///             }
+///             _ => Ok(0),
+///         }
+///     }
+/// }
+///
+/// let mut r = HexReader::new(OneByteAtATime(b"abCD1234"));
+/// let mut buf = Vec::new();
+/// r.read_to_end(&mut buf)?;
+/// assert_eq!(buf, [0xab, 0xcd, 0x12, 0x34]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// A trailing, unpaired hex digit is a truncated byte and is rejected
+/// rather than silently dropped:
+/// ```
+/// use base16ct::io::HexReader;
+/// use std::io::Read;
+///
+/// let mut r = HexReader::new(&b"abc"[..]);
+/// let mut buf = Vec::new();
+/// let err = r.read_to_end(&mut buf).unwrap_err();
+/// assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+/// // the one complete byte pair read before the dangling `c` is preserved
+/// assert_eq!(buf, [0xab]);
+/// ```
+pub struct HexReader<R> {
+    inner: R,
+    buf: [u8; BUF_LEN],
+    buf_pos: usize,
+    buf_len: usize,
+    pending_nibble: Option<u8>,
+}
+
+impl<R: Read> HexReader<R> {
+    /// Wrap the given reader.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: [0u8; BUF_LEN],
+            buf_pos: 0,
+            buf_len: 0,
+            pending_nibble: None,
+        }
+    }
+
+    /// Consume `self`, returning the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn next_hex_byte(&mut self) -> io::Result<Option<u8>> {
+        if self.buf_pos == self.buf_len {
+            self.buf_len = self.inner.read(&mut self.buf)?;
+            self.buf_pos = 0;
+
+            if self.buf_len == 0 {
+                return Ok(None);
+            }
+        }
+
+        let byte = self.buf[self.buf_pos];
+        self.buf_pos += 1;
+        Ok(Some(byte))
+    }
+}
+
+impl<R: Read> Read for HexReader<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+
+        while written < out.len() {
+            let hex_byte = match self.next_hex_byte()? {
+                Some(hex_byte) => hex_byte,
+                // A dangling high nibble at EOF is a truncated byte pair.
+                // Hand back what's already decoded first (if anything);
+                // the error surfaces on the next call once there's
+                // nothing left to return but the truncation.
+                None if self.pending_nibble.is_some() && written == 0 => {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, Error::InvalidEncoding));
+                }
+                None => break,
+            };
+
+            let nibble = mixed::decode_nibble(hex_byte);
+            if nibble & 0xff00 != 0 {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, Error::InvalidEncoding));
+            }
+            let nibble = nibble as u8;
+
+            match self.pending_nibble.take() {
+                Some(hi) => {
+                    out[written] = (hi << 4) | nibble;
+                    written += 1;
+                }
+                None => self.pending_nibble = Some(nibble),
+            }
+        }
+
+        Ok(written)
+    }
+}
@@ -53,16 +53,13 @@
     html_root_url = "https://docs.rs/base16ct/0.1.0"
 )]
 
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", feature = "serde"))]
 #[macro_use]
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-use core::fmt;
-
-#[cfg(feature = "alloc")]
-use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write as _};
 
 /// Fucntion for decoding and encoding lower Base16 (hex)
 pub mod lower;
@@ -71,6 +68,66 @@ pub mod mixed;
 /// Fucntion for decoding and encoding upper Base16 (hex)
 pub mod upper;
 
+mod encoder;
+pub use encoder::{BufEncoder, Case, OutBytes};
+
+mod traits;
+pub use traits::{FromHex, ToHex};
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod io;
+
+/// Wrapper around a byte slice which prints it as Base16 (hex) when passed
+/// to one of `core::fmt`'s formatting macros, without allocating an
+/// intermediate buffer or `String`.
+///
+/// Uses the same data-independent nibble encoding as [`lower`] and
+/// [`upper`], so formatting stays "best effort" constant-time with respect
+/// to the byte values (not their count).
+///
+/// # Examples
+/// ```
+/// use base16ct::HexDisplay;
+///
+/// let bytes = b"\xab\xcd\x12\x34";
+/// assert_eq!(format!("{}", HexDisplay(bytes)), "abcd1234");
+/// assert_eq!(format!("{:x}", HexDisplay(bytes)), "abcd1234");
+/// assert_eq!(format!("{:X}", HexDisplay(bytes)), "ABCD1234");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HexDisplay<'a>(pub &'a [u8]);
+
+impl fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            f.write_char(lower::encode_nibble(byte >> 4) as char)?;
+            f.write_char(lower::encode_nibble(byte & 0xf) as char)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            f.write_char(upper::encode_nibble(byte >> 4) as char)?;
+            f.write_char(upper::encode_nibble(byte & 0xf) as char)?;
+        }
+        Ok(())
+    }
+}
+
 /// Compute decoded length of the given hex-encoded input.
 #[inline(always)]
 pub fn decoded_len(bytes: &[u8]) -> Result<usize, Error> {
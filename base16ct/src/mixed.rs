@@ -0,0 +1,80 @@
+//! Mixed-case Base16 (hex) decoding.
+//!
+//! Accepts hex input containing either lower or upper case digits (or a mix
+//! of both). There is no corresponding `encode` function: callers should use
+//! [`crate::lower`] or [`crate::upper`] to pick the desired output case.
+
+use crate::{decode_inner, lower, upper, Error};
+
+#[cfg(feature = "alloc")]
+use {alloc::vec::Vec, crate::decoded_len};
+
+/// Decode a mixed-case hex-encoded string into the provided destination
+/// buffer.
+pub fn decode<T: AsRef<[u8]>>(src: T, dst: &mut [u8]) -> Result<&[u8], Error> {
+    decode_inner(src.as_ref(), dst, decode_nibble)
+}
+
+/// Decode a mixed-case hex-encoded string into a byte vector.
+#[cfg(feature = "alloc")]
+pub fn decode_vec<T: AsRef<[u8]>>(src: T) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; decoded_len(src.as_ref())?];
+    decode(src, &mut output)?;
+    Ok(output)
+}
+
+/// Decode a mixed-case hex-encoded byte string into a fixed-size array,
+/// usable in `const` context, e.g.:
+///
+/// ```
+/// const EXAMPLE: [u8; 4] = base16ct::mixed::decode_const(b"abCD1234");
+/// assert_eq!(EXAMPLE, [0xab, 0xcd, 0x12, 0x34]);
+/// ```
+///
+/// # Panics
+/// Panics if `src` does not decode to exactly `N` bytes, or if it contains
+/// an invalid hex digit.
+pub const fn decode_const<const N: usize>(src: &[u8]) -> [u8; N] {
+    match try_decode_const(src) {
+        Ok(array) => array,
+        Err(_) => panic!("invalid Base16 (hex) input"),
+    }
+}
+
+/// Fallible `const fn` counterpart to [`decode_const`].
+pub const fn try_decode_const<const N: usize>(src: &[u8]) -> Result<[u8; N], Error> {
+    if src.len() != N * 2 {
+        return Err(Error::InvalidLength);
+    }
+
+    let mut out = [0u8; N];
+    let mut i = 0;
+
+    while i < N {
+        let hi = decode_nibble(src[i * 2]);
+        let lo = decode_nibble(src[i * 2 + 1]);
+        let byte = (hi << 4) | lo;
+
+        if byte & 0xff00 != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+
+        out[i] = byte as u8;
+        i += 1;
+    }
+
+    Ok(out)
+}
+
+/// Decode a single nibble of mixed case hex.
+///
+/// Invalid nibbles are encoded as `0xff` in the upper byte by both
+/// [`lower::decode_nibble`] and [`upper::decode_nibble`], so a char that's
+/// valid in exactly one case survives the `&`, while a char invalid in both
+/// keeps its error bits set.
+#[inline(always)]
+pub(crate) const fn decode_nibble(src: u8) -> u16 {
+    let lower = lower::decode_nibble(src);
+    let upper = upper::decode_nibble(src);
+    lower & upper
+}
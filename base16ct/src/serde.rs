@@ -0,0 +1,153 @@
+//! Optional [`serde`] support for hex (de)serialization of byte containers.
+//!
+//! Intended for use with `#[serde(with = "base16ct::serde")]` on a field of
+//! type `[u8; N]` or (given `alloc`) `Vec<u8>`. In human-readable formats
+//! (e.g. JSON) the field round-trips through a hex string; in binary
+//! formats (e.g. bincode) it's (de)serialized as raw bytes instead, so this
+//! only costs a hex encode/decode where a human is actually going to read
+//! it.
+//!
+//! Deserialization accepts either upper or lower case hex (or a mix of
+//! both), via the [`mixed`][`crate::mixed`] decoder.
+//!
+//! Building the hex `String` for the human-readable path always needs an
+//! allocator, so the `serde` feature links the `alloc` crate
+//! unconditionally; `Vec<u8>` support on top of that stays gated on the
+//! `alloc` feature like the rest of the crate.
+//!
+//! # Examples
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+//! struct Example {
+//!     #[serde(with = "base16ct::serde")]
+//!     key: [u8; 4],
+//! }
+//!
+//! let original = Example { key: [0xab, 0xcd, 0x12, 0x34] };
+//!
+//! // human-readable formats round-trip through a hex string
+//! let json = serde_json::to_string(&original)?;
+//! assert_eq!(json, r#"{"key":"abcd1234"}"#);
+//! assert_eq!(serde_json::from_str::<Example>(&json)?, original);
+//!
+//! // either case is accepted coming back in
+//! assert_eq!(serde_json::from_str::<Example>(r#"{"key":"ABcd1234"}"#)?.key, original.key);
+//!
+//! // binary formats skip the hex round-trip entirely
+//! let bytes = bincode::serialize(&original).unwrap();
+//! assert_eq!(bincode::deserialize::<Example>(&bytes).unwrap(), original);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! `serialize_upper` produces upper-case hex on the human-readable path:
+//! ```
+//! #[derive(serde::Serialize)]
+//! struct ExampleUpper {
+//!     #[serde(serialize_with = "base16ct::serde::serialize_upper")]
+//!     key: [u8; 4],
+//! }
+//!
+//! let json = serde_json::to_string(&ExampleUpper { key: [0xab, 0xcd, 0x12, 0x34] })?;
+//! assert_eq!(json, r#"{"key":"ABCD1234"}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{Error, FromHex, ToHex};
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
+use serde::{de, Deserializer, Serializer};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Serialize the given byte container as lower-case hex.
+pub fn serialize<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    serialize_with_case(bytes, serializer, false)
+}
+
+/// Serialize the given byte container as upper-case hex.
+pub fn serialize_upper<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    serialize_with_case(bytes, serializer, true)
+}
+
+fn serialize_with_case<S, T>(bytes: &T, serializer: S, upper: bool) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]>,
+{
+    let bytes = bytes.as_ref();
+
+    if serializer.is_human_readable() {
+        let hex: String = if upper {
+            bytes.encode_hex_upper()
+        } else {
+            bytes.encode_hex()
+        };
+        serializer.serialize_str(&hex)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserialize a hex string (in a human-readable format) or raw bytes (in a
+/// binary format) into `T`.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromHex + FromRawBytes,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(HexVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(HexVisitor(PhantomData))
+    }
+}
+
+struct HexVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromHex + FromRawBytes> de::Visitor<'de> for HexVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex-encoded string or raw bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_hex(v).map_err(de::Error::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        T::from_raw_bytes(v).ok_or_else(|| de::Error::custom(Error::InvalidLength))
+    }
+}
+
+/// Construct `Self` directly from a slice of already-decoded bytes.
+///
+/// Used by [`deserialize`] for binary (non-human-readable) formats, which
+/// carry raw bytes rather than a hex string.
+pub trait FromRawBytes: Sized {
+    /// Attempt to construct `Self` from `bytes`, failing if the length
+    /// doesn't match.
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+impl<const N: usize> FromRawBytes for [u8; N] {
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromRawBytes for Vec<u8> {
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(bytes.to_vec())
+    }
+}
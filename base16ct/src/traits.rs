@@ -0,0 +1,103 @@
+//! Generic hex conversion traits.
+
+use crate::{decoded_len, lower, mixed, upper, Error};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Encode `self` as a hexadecimal string, collecting the output into any
+/// `T: FromIterator<char>` (e.g. `String`).
+///
+/// # Examples
+/// ```
+/// use base16ct::ToHex;
+///
+/// let bytes = [0xab, 0xcd, 0x12, 0x34];
+/// let hex: String = bytes.encode_hex();
+/// assert_eq!(hex, "abcd1234");
+/// let hex_upper: String = bytes.encode_hex_upper();
+/// assert_eq!(hex_upper, "ABCD1234");
+/// ```
+pub trait ToHex {
+    /// Encode `self` as a lower-case hex string.
+    fn encode_hex<T: FromIterator<char>>(&self) -> T;
+
+    /// Encode `self` as an upper-case hex string.
+    fn encode_hex_upper<T: FromIterator<char>>(&self) -> T;
+}
+
+impl ToHex for [u8] {
+    fn encode_hex<T: FromIterator<char>>(&self) -> T {
+        hex_chars(self, lower::encode_nibble).collect()
+    }
+
+    fn encode_hex_upper<T: FromIterator<char>>(&self) -> T {
+        hex_chars(self, upper::encode_nibble).collect()
+    }
+}
+
+impl<const N: usize> ToHex for [u8; N] {
+    fn encode_hex<T: FromIterator<char>>(&self) -> T {
+        self.as_slice().encode_hex()
+    }
+
+    fn encode_hex_upper<T: FromIterator<char>>(&self) -> T {
+        self.as_slice().encode_hex_upper()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToHex for Vec<u8> {
+    fn encode_hex<T: FromIterator<char>>(&self) -> T {
+        self.as_slice().encode_hex()
+    }
+
+    fn encode_hex_upper<T: FromIterator<char>>(&self) -> T {
+        self.as_slice().encode_hex_upper()
+    }
+}
+
+fn hex_chars(src: &[u8], encode_nibble: fn(u8) -> u8) -> impl Iterator<Item = char> + '_ {
+    src.iter()
+        .flat_map(move |byte| [encode_nibble(byte >> 4) as char, encode_nibble(byte & 0xf) as char])
+}
+
+/// Decode a hexadecimal string into `Self`, accepting either upper or lower
+/// case (or a mix of both) via [`mixed`].
+///
+/// # Examples
+/// ```
+/// use base16ct::FromHex;
+///
+/// let key: [u8; 4] = FromHex::from_hex("abCD1234").unwrap();
+/// assert_eq!(key, [0xab, 0xcd, 0x12, 0x34]);
+///
+/// // wrong output length is rejected rather than silently truncated
+/// let err = <[u8; 4]>::from_hex("abcd123456").unwrap_err();
+/// assert_eq!(err, base16ct::Error::InvalidLength);
+/// ```
+pub trait FromHex: Sized {
+    /// Decode the given hex-encoded input.
+    fn from_hex<T: AsRef<[u8]>>(input: T) -> Result<Self, Error>;
+}
+
+impl<const N: usize> FromHex for [u8; N] {
+    fn from_hex<T: AsRef<[u8]>>(input: T) -> Result<Self, Error> {
+        let input = input.as_ref();
+
+        if decoded_len(input)? != N {
+            return Err(Error::InvalidLength);
+        }
+
+        let mut output = [0u8; N];
+        mixed::decode(input, &mut output)?;
+        Ok(output)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromHex for Vec<u8> {
+    fn from_hex<T: AsRef<[u8]>>(input: T) -> Result<Self, Error> {
+        mixed::decode_vec(input)
+    }
+}
@@ -0,0 +1,150 @@
+//! Upper-case Base16 (hex) encoding and decoding.
+
+use crate::{decode_inner, encoded_len, Error};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::{string::String, vec::Vec},
+    crate::decoded_len,
+};
+
+/// Decode an upper-case hex-encoded string into the provided destination
+/// buffer.
+pub fn decode<T: AsRef<[u8]>>(src: T, dst: &mut [u8]) -> Result<&[u8], Error> {
+    decode_inner(src.as_ref(), dst, decode_nibble)
+}
+
+/// Decode an upper-case hex-encoded string into a byte vector.
+#[cfg(feature = "alloc")]
+pub fn decode_vec<T: AsRef<[u8]>>(src: T) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; decoded_len(src.as_ref())?];
+    decode(src, &mut output)?;
+    Ok(output)
+}
+
+/// Encode the input byte slice as upper case hex into the provided
+/// destination buffer, returning the encoded output as a byte slice.
+pub fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a [u8], Error> {
+    let dst = dst.get_mut(..encoded_len(src)).ok_or(Error::InvalidLength)?;
+
+    for (src, dst) in src.iter().zip(dst.chunks_exact_mut(2)) {
+        let (hi, lo) = dst.split_at_mut(1);
+        hi[0] = encode_nibble(src >> 4);
+        lo[0] = encode_nibble(src & 0xf);
+    }
+
+    Ok(dst)
+}
+
+/// Encode the input byte slice as upper case hex into the provided
+/// destination buffer, returning the encoded output as a `str`.
+pub fn encode_str<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
+    let output = encode(src, dst)?;
+    debug_assert!(core::str::from_utf8(output).is_ok());
+    Ok(unsafe { core::str::from_utf8_unchecked(output) })
+}
+
+/// Encode the input byte slice as an upper-case hex-encoded [`String`].
+#[cfg(feature = "alloc")]
+pub fn encode_string(src: &[u8]) -> String {
+    let elen = encoded_len(src);
+    let mut dst = vec![0u8; elen];
+    let res = encode_str(src, &mut dst).expect("encoding error");
+
+    debug_assert_eq!(res.len(), elen);
+    res.into()
+}
+
+/// Decode an upper-case hex-encoded byte string into a fixed-size array,
+/// usable in `const` context, e.g.:
+///
+/// ```
+/// const EXAMPLE: [u8; 4] = base16ct::upper::decode_const(b"ABCD1234");
+/// assert_eq!(EXAMPLE, [0xab, 0xcd, 0x12, 0x34]);
+/// ```
+///
+/// # Panics
+/// Panics if `src` does not decode to exactly `N` bytes, or if it contains
+/// an invalid upper-case hex digit.
+pub const fn decode_const<const N: usize>(src: &[u8]) -> [u8; N] {
+    match try_decode_const(src) {
+        Ok(array) => array,
+        Err(_) => panic!("invalid upper-case Base16 (hex) input"),
+    }
+}
+
+/// Fallible `const fn` counterpart to [`decode_const`].
+pub const fn try_decode_const<const N: usize>(src: &[u8]) -> Result<[u8; N], Error> {
+    if src.len() != N * 2 {
+        return Err(Error::InvalidLength);
+    }
+
+    let mut out = [0u8; N];
+    let mut i = 0;
+
+    while i < N {
+        let hi = decode_nibble(src[i * 2]);
+        let lo = decode_nibble(src[i * 2 + 1]);
+        let byte = (hi << 4) | lo;
+
+        if byte & 0xff00 != 0 {
+            return Err(Error::InvalidEncoding);
+        }
+
+        out[i] = byte as u8;
+        i += 1;
+    }
+
+    Ok(out)
+}
+
+/// Encode the input byte array as upper-case hex into a caller-sized
+/// output array, usable in `const` context, e.g.:
+///
+/// ```
+/// const EXAMPLE: [u8; 8] = base16ct::upper::encode_const(&[0xab, 0xcd, 0x12, 0x34]);
+/// assert_eq!(&EXAMPLE, b"ABCD1234");
+/// ```
+///
+/// # Panics
+/// Panics if the output array length `M` is not exactly twice the input
+/// array length `N`.
+pub const fn encode_const<const N: usize, const M: usize>(src: &[u8; N]) -> [u8; M] {
+    assert!(M == N * 2, "output array length must be exactly twice the input length");
+
+    let mut out = [0u8; M];
+    let mut i = 0;
+
+    while i < N {
+        out[i * 2] = encode_nibble(src[i] >> 4);
+        out[i * 2 + 1] = encode_nibble(src[i] & 0xf);
+        i += 1;
+    }
+
+    out
+}
+
+/// Decode a single nibble of upper case hex.
+#[inline(always)]
+pub(crate) const fn decode_nibble(src: u8) -> u16 {
+    let byte = src as i16;
+    let mut ret: i16 = -1;
+
+    // 0-9  (0x30-0x39)
+    ret += (((0x2fi16 - byte) & (byte - 0x3a)) >> 8) & (byte - 47);
+    // A-F  (0x41-0x46)
+    ret += (((0x40i16 - byte) & (byte - 0x47)) >> 8) & (byte - 54);
+
+    ret as u16
+}
+
+/// Encode a single nibble as upper case hex.
+#[inline(always)]
+pub(crate) const fn encode_nibble(src: u8) -> u8 {
+    let src = src as i16;
+    let mut ret: i16 = src + 0x30;
+
+    ret += ((9 - src) >> 8) & 7;
+
+    ret as u8
+}